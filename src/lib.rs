@@ -1,23 +1,549 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput, Data, Fields, Ident};
 
+/// Returns `true` if `attrs` contains `#[reflect(skip)]` or `#[reflect(ignore)]`.
+fn is_reflect_skip(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path().is_ident("reflect") {
+            return false;
+        }
+
+        let mut skip = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") || meta.path.is_ident("ignore") {
+                skip = true;
+            }
+            Ok(())
+        });
+        skip
+    })
+}
+
+/// A single reflected field of a variant, surviving `#[reflect(skip)]` filtering.
+struct ReflectField {
+    /// The identifier bound to this field in the match pattern.
+    binding: Ident,
+    /// The name used to look this field up (the field name, or its source index for tuple fields).
+    display_name: String,
+}
+
+/// The destructuring pattern and reflected fields for one variant, with skipped
+/// fields and variants already filtered out.
+struct VariantPlan {
+    /// The pattern to match this variant, e.g. `Foo { a, b, .. }` or `Bar(f0, _, f2)`.
+    pattern: TokenStream2,
+    /// Fields exposed to reflection, in `get_fields()` order.
+    fields: Vec<ReflectField>,
+}
+
+/// Builds the [`VariantPlan`] for `v`, honoring `#[reflect(skip)]`/`#[reflect(ignore)]`
+/// on the variant itself and on its individual fields.
+fn plan_variant(v: &syn::Variant) -> VariantPlan {
+    let variant_ident = &v.ident;
+
+    if is_reflect_skip(&v.attrs) {
+        let pattern = match &v.fields {
+            Fields::Named(_) => quote! { #variant_ident { .. } },
+            Fields::Unnamed(_) => quote! { #variant_ident(..) },
+            Fields::Unit => quote! { #variant_ident },
+        };
+        return VariantPlan { pattern, fields: vec![] };
+    }
+
+    match &v.fields {
+        Fields::Named(fields_named) => {
+            let bindings: Vec<Ident> = fields_named.named.iter()
+                .filter(|f| !is_reflect_skip(&f.attrs))
+                .map(|f| f.ident.clone().unwrap())
+                .collect();
+            let has_skipped = bindings.len() < fields_named.named.len();
+
+            let pattern = if bindings.is_empty() {
+                if has_skipped {
+                    quote! { #variant_ident { .. } }
+                } else {
+                    quote! { #variant_ident {} }
+                }
+            } else if has_skipped {
+                quote! { #variant_ident { #(#bindings),*, .. } }
+            } else {
+                quote! { #variant_ident { #(#bindings),* } }
+            };
+
+            let fields = bindings.into_iter().map(|ident| {
+                let display_name = ident.to_string();
+                ReflectField { binding: ident, display_name }
+            }).collect();
+
+            VariantPlan { pattern, fields }
+        }
+
+        Fields::Unnamed(fields_unnamed) => {
+            let slots: Vec<_> = fields_unnamed.unnamed.iter().enumerate().map(|(i, f)| {
+                if is_reflect_skip(&f.attrs) {
+                    quote! { _ }
+                } else {
+                    let ident = Ident::new(&format!("f{}", i), variant_ident.span());
+                    quote! { #ident }
+                }
+            }).collect();
+
+            let fields = fields_unnamed.unnamed.iter().enumerate()
+                .filter(|(_, f)| !is_reflect_skip(&f.attrs))
+                .map(|(i, _)| ReflectField {
+                    binding: Ident::new(&format!("f{}", i), variant_ident.span()),
+                    display_name: i.to_string(),
+                })
+                .collect();
+
+            VariantPlan { pattern: quote! { #variant_ident( #(#slots),* ) }, fields }
+        }
+
+        Fields::Unit => VariantPlan { pattern: quote! { #variant_ident }, fields: vec![] },
+    }
+}
+
+/// The variant's pattern with all fields replaced by a wildcard, matching the variant
+/// without binding any of its fields, e.g. `Foo { .. }`, `Bar(..)`, or `Baz`.
+fn wildcard_pattern(v: &syn::Variant) -> TokenStream2 {
+    let variant_ident = &v.ident;
+    match &v.fields {
+        Fields::Named(_) => quote! { #variant_ident { .. } },
+        Fields::Unnamed(_) => quote! { #variant_ident(..) },
+        Fields::Unit => quote! { #variant_ident },
+    }
+}
+
+/// Converts a `PascalCase` variant name into `snake_case` for the `is_*`/`as_*`/`into_*`
+/// method names (e.g. `HttpRequest` -> `http_request`).
+fn to_snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut result = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                let prev = chars[i - 1];
+                let next = chars.get(i + 1);
+                if prev.is_lowercase() || prev.is_numeric()
+                    || (prev.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+                {
+                    result.push('_');
+                }
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// The fields of a variant as typed (ident, type, name) triples, in declaration order,
+/// for use by the `is_*`/`as_*`/`into_*` accessors and the `DynamicEnum` patch machinery.
+/// Not affected by `#[reflect(skip)]`, since these are compile-time typed helpers
+/// rather than `dyn Any` reflection.
+struct TypedField {
+    binding: Ident,
+    ty: syn::Type,
+    display_name: String,
+}
+
+fn typed_fields(v: &syn::Variant) -> Vec<TypedField> {
+    match &v.fields {
+        Fields::Named(fields_named) => fields_named.named.iter().map(|f| {
+            let ident = f.ident.clone().unwrap();
+            let display_name = ident.to_string();
+            TypedField { binding: ident, ty: f.ty.clone(), display_name }
+        }).collect(),
+
+        Fields::Unnamed(fields_unnamed) => fields_unnamed.unnamed.iter().enumerate().map(|(i, f)| {
+            TypedField {
+                binding: Ident::new(&format!("f{}", i), v.ident.span()),
+                ty: f.ty.clone(),
+                display_name: i.to_string(),
+            }
+        }).collect(),
+
+        Fields::Unit => vec![],
+    }
+}
+
+/// Builds the `apply`-in-place match arm for one variant: when the patch targets the
+/// variant already active on `self`, downcast each named field from the patch and
+/// overwrite it in place. Like `from_dynamic`, every field of the variant must be
+/// present in the patch and downcast to the field's type, or the whole `apply` call
+/// fails — this mirrors `from_dynamic`'s "missing fields fail, don't default" and
+/// "type mismatches fail" invariants instead of silently applying a partial patch.
+///
+/// A variant marked `#[reflect(skip)]` is rejected outright: reflection doesn't expose
+/// it, so there is nothing for a patch to legitimately target.
+///
+/// Patch fields are moved out of `patch.fields` and downcast by value (rather than
+/// cloned), so this doesn't require field types to implement `Clone`.
+fn apply_same_variant_arm(enum_ident: &Ident, v: &syn::Variant) -> TokenStream2 {
+    let variant_ident = &v.ident;
+    let variant_str = variant_ident.to_string();
+
+    if is_reflect_skip(&v.attrs) {
+        let wildcard = wildcard_pattern(v);
+        return quote! {
+            #enum_ident::#wildcard => {
+                return Err(enum_reflect_extetn::DynamicEnumError::UnknownVariant(#variant_str.to_string()));
+            }
+        };
+    }
+
+    let fields = typed_fields(v);
+
+    let pattern = match &v.fields {
+        Fields::Named(_) => {
+            let bindings: Vec<&Ident> = fields.iter().map(|f| &f.binding).collect();
+            quote! { #enum_ident::#variant_ident { #(#bindings),* } }
+        }
+        Fields::Unnamed(_) => {
+            let bindings: Vec<&Ident> = fields.iter().map(|f| &f.binding).collect();
+            quote! { #enum_ident::#variant_ident( #(#bindings),* ) }
+        }
+        Fields::Unit => quote! { #enum_ident::#variant_ident },
+    };
+
+    // Every value is taken and downcast before any field is written, so an error on
+    // a later field can't leave earlier ones already overwritten.
+    let staged: Vec<Ident> = (0..fields.len())
+        .map(|i| quote::format_ident!("__patch_value_{}", i))
+        .collect();
+
+    let takes = fields.iter().zip(&staged).map(|(f, staged)| {
+        let ty = &f.ty;
+        let name_str = &f.display_name;
+        quote! {
+            let #staged = *patch.fields.remove(#name_str)
+                .ok_or_else(|| enum_reflect_extetn::DynamicEnumError::MissingField(#name_str))?
+                .downcast::<#ty>()
+                .map_err(|_| enum_reflect_extetn::DynamicEnumError::TypeMismatch(#name_str))?;
+        }
+    });
+
+    let overwrites = fields.iter().zip(&staged).map(|(f, staged)| {
+        let binding = &f.binding;
+        quote! { *#binding = #staged; }
+    });
+
+    quote! {
+        #pattern => {
+            #(#takes)*
+            #(#overwrites)*
+        }
+    }
+}
+
+/// Builds the `from_dynamic` match arm for one variant: construct it from the patch's
+/// name-keyed fields, requiring every field to be present and to downcast cleanly.
+///
+/// A variant marked `#[reflect(skip)]` can't be constructed this way at all — it's
+/// treated the same as a name that doesn't exist on the enum, since reflection (which
+/// is all `DynamicEnum` has to go on) doesn't expose the variant in the first place.
+///
+/// Patch fields are moved out of `dynamic.fields` and downcast by value (rather than
+/// cloned), so this doesn't require field types to implement `Clone`.
+fn from_dynamic_arm(enum_ident: &Ident, v: &syn::Variant) -> TokenStream2 {
+    let variant_ident = &v.ident;
+    let variant_str = variant_ident.to_string();
+
+    if is_reflect_skip(&v.attrs) {
+        return quote! {
+            #variant_str => Err(enum_reflect_extetn::DynamicEnumError::UnknownVariant(#variant_str.to_string())),
+        };
+    }
+
+    let fields = typed_fields(v);
+
+    let field_bindings = fields.iter().map(|f| {
+        let binding = &f.binding;
+        let ty = &f.ty;
+        let name_str = &f.display_name;
+        quote! {
+            let #binding = *dynamic.fields.remove(#name_str)
+                .ok_or_else(|| enum_reflect_extetn::DynamicEnumError::MissingField(#name_str))?
+                .downcast::<#ty>()
+                .map_err(|_| enum_reflect_extetn::DynamicEnumError::TypeMismatch(#name_str))?;
+        }
+    });
+
+    let construct = match &v.fields {
+        Fields::Named(_) => {
+            let bindings: Vec<&Ident> = fields.iter().map(|f| &f.binding).collect();
+            quote! { #enum_ident::#variant_ident { #(#bindings),* } }
+        }
+        Fields::Unnamed(_) => {
+            let bindings: Vec<&Ident> = fields.iter().map(|f| &f.binding).collect();
+            quote! { #enum_ident::#variant_ident( #(#bindings),* ) }
+        }
+        Fields::Unit => quote! { #enum_ident::#variant_ident },
+    };
+
+    quote! {
+        #variant_str => {
+            #(#field_bindings)*
+            Ok(#construct)
+        }
+    }
+}
+
+/// Builds the `is_*`/`as_*`/`as_*_mut`/`into_*` accessor methods for one variant.
+///
+/// A variant marked `#[reflect(skip)]` gets none of these, consistent with it being
+/// excluded from every other generated accessor.
+fn variant_accessor_methods(enum_ident: &Ident, v: &syn::Variant) -> TokenStream2 {
+    if is_reflect_skip(&v.attrs) {
+        return TokenStream2::new();
+    }
+
+    let variant_ident = &v.ident;
+    let snake = to_snake_case(&variant_ident.to_string());
+    let is_ident = quote::format_ident!("is_{}", snake);
+
+    let wildcard = wildcard_pattern(v);
+    let is_pattern = quote! { #enum_ident::#wildcard };
+
+    let is_method = quote! {
+        pub fn #is_ident(&self) -> bool {
+            match self {
+                #is_pattern => true,
+                _ => false,
+            }
+        }
+    };
+
+    if matches!(v.fields, Fields::Unit) {
+        return is_method;
+    }
+
+    let as_ident = quote::format_ident!("as_{}", snake);
+    let as_mut_ident = quote::format_ident!("as_{}_mut", snake);
+    let into_ident = quote::format_ident!("into_{}", snake);
+
+    let fields = typed_fields(v);
+    let bindings: Vec<&Ident> = fields.iter().map(|f| &f.binding).collect();
+    let types: Vec<&syn::Type> = fields.iter().map(|f| &f.ty).collect();
+
+    let pattern = match &v.fields {
+        Fields::Named(_) => quote! { #enum_ident::#variant_ident { #(#bindings),* } },
+        Fields::Unnamed(_) => quote! { #enum_ident::#variant_ident( #(#bindings),* ) },
+        Fields::Unit => unreachable!(),
+    };
+
+    let (as_ty, as_ok, as_mut_ty, as_mut_ok, into_ty, into_ok) = if types.len() == 1 {
+        let ty = types[0];
+        let binding = bindings[0];
+        (
+            quote! { &#ty },
+            quote! { #binding },
+            quote! { &mut #ty },
+            quote! { #binding },
+            quote! { #ty },
+            quote! { #binding },
+        )
+    } else {
+        (
+            quote! { (#(&#types),*) },
+            quote! { (#(#bindings),*) },
+            quote! { (#(&mut #types),*) },
+            quote! { (#(#bindings),*) },
+            quote! { (#(#types),*) },
+            quote! { (#(#bindings),*) },
+        )
+    };
+
+    quote! {
+        #is_method
+
+        pub fn #as_ident(&self) -> Option<#as_ty> {
+            match self {
+                #pattern => Some(#as_ok),
+                _ => None,
+            }
+        }
+
+        pub fn #as_mut_ident(&mut self) -> Option<#as_mut_ty> {
+            match self {
+                #pattern => Some(#as_mut_ok),
+                _ => None,
+            }
+        }
+
+        pub fn #into_ident(self) -> Result<#into_ty, Self> {
+            match self {
+                #pattern => Ok(#into_ok),
+                other => Err(other),
+            }
+        }
+    }
+}
+
 /// Derive reflection for enum fields to access name and value.
 /// Use mut functions to get mutable fields.
-/// 
+///
 /// # Implementing
-/// 
+///
 /// ```
 /// #[derive(EnumReflect)]
 /// enum ExampleEnum;
 /// ```
 /// # Functions
-/// 
+///
 /// - `fn get_fields() -> Vec<&mut dyn std::any::Any>` Return immutable field values
 /// - `fn get_named_fields() -> Vec<(&'static str, &mut dyn std::any::Any)>` Return field names and immutable field values
 /// - `fn get_fields_mut() -> Vec<&mut dyn std::any::Any>` Return mutable field values
 /// - `fn get_named_fields_mut() -> Vec<(&'static str, &mut dyn std::any::Any)>` Return field names and mutable field values
 ///
+/// # `EnumReflect` trait
+///
+/// The methods above are paired with an `EnumReflect` trait impl that exposes the
+/// same data through a fixed, `bevy_reflect`-style surface:
+///
+/// - `fn variant_name(&self) -> &'static str` Return the name of the active variant
+/// - `fn field_len(&self) -> usize` Return the number of fields on the active variant
+/// - `fn field(&self, name: &str) -> Option<&dyn std::any::Any>` Look up a field by name (tuple fields use their index, e.g. `"0"`)
+/// - `fn field_at(&self, index: usize) -> Option<&dyn std::any::Any>` Look up a field by position
+/// - `fn field_mut(&mut self, name: &str) -> Option<&mut dyn std::any::Any>` Mutable form of `field`
+/// - `fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn std::any::Any>` Mutable form of `field_at`
+///
+/// `field_len` and the lookup methods always agree with the order returned by `get_fields`.
+///
+/// # Skipping fields and variants
+///
+/// Annotate a field or a whole variant with `#[reflect(skip)]` (or `#[reflect(ignore)]`)
+/// to leave it out of every `dyn Any` reflection accessor. A skipped variant also gets
+/// none of the `is_*`/`as_*`/`into_*` typed accessors below, since they would otherwise
+/// expose the very fields the reflection API is hiding; a skipped field still appears
+/// in those typed accessors and in `apply`/`from_dynamic`. Tuple-variant fields
+/// keep their original source index as their lookup name even when an earlier field in
+/// the same variant is skipped, so indices never shift around.
+///
+/// ```
+/// #[derive(EnumReflect)]
+/// enum Credential {
+///     Public { name: String },
+///     #[reflect(skip)]
+///     Secret { token: String },
+/// }
+///
+/// let public = Credential::Public { name: "alice".to_string() };
+/// assert_eq!(public.get_named_fields().len(), 1);
+///
+/// let secret = Credential::Secret { token: "xyz".to_string() };
+/// assert_eq!(secret.get_named_fields().len(), 0);
+/// assert_eq!(secret.field_len(), 0);
+/// ```
+///
+/// # Typed variant accessors
+///
+/// Besides the `dyn Any`-based reflection API, each variant gets `enum-as-inner`-style
+/// typed helpers named after its `snake_case` variant name:
+///
+/// - `fn is_variant_name(&self) -> bool`
+/// - `fn as_variant_name(&self) -> Option<(&T1, &T2, ..)>` (the bare `&T` for a single field)
+/// - `fn as_variant_name_mut(&mut self) -> Option<(&mut T1, &mut T2, ..)>`
+/// - `fn into_variant_name(self) -> Result<(T1, T2, ..), Self>`
+///
+/// Unit variants only get `is_variant_name`, since there is nothing to extract. A
+/// variant marked `#[reflect(skip)]` gets none of these, matching the `dyn Any` API;
+/// skipping an individual field (rather than the whole variant) has no effect here,
+/// since these are compile-time typed helpers rather than reflected members.
+///
+/// # Dynamic patching
+///
+/// The derive also emits `apply`/`from_dynamic`, ported from the patch model used by
+/// `mirror-mirror` and `bevy_reflect`. Both take the patch by value and move each field's
+/// `Box<dyn Any>` out to downcast it, so field types need no `Clone` bound:
+///
+/// - `fn apply(&mut self, patch: enum_reflect_extetn::DynamicEnum) -> Result<(), enum_reflect_extetn::DynamicEnumError>`
+///   When `patch` targets the variant already active on `self`, every one of that
+///   variant's fields is downcast from the patch and overwritten in place; otherwise
+///   `self` is replaced by a freshly constructed variant built from `patch` via
+///   `from_dynamic`. Either way, a missing field or a type mismatch fails the whole
+///   call with `DynamicEnumError` and leaves `self` unchanged — `apply` never
+///   partially patches a variant.
+/// - `fn from_dynamic(dynamic: enum_reflect_extetn::DynamicEnum) -> Result<Self, enum_reflect_extetn::DynamicEnumError>`
+///   Builds a whole variant from a name-keyed collection of `Box<dyn Any>` values,
+///   failing with `DynamicEnumError::MissingField` or `DynamicEnumError::TypeMismatch`
+///   rather than silently defaulting.
+///
+/// A variant marked `#[reflect(skip)]` can be neither constructed via `from_dynamic`
+/// nor patched via `apply` — both fail with `DynamicEnumError::UnknownVariant`, since
+/// reflection doesn't expose the variant for `DynamicEnum` to target in the first place.
+///
+/// ```ignore
+/// #[derive(EnumReflect)]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Square { side: f64 },
+/// }
+///
+/// let mut shape = Shape::Circle { radius: 1.0 };
+///
+/// // Same-variant patch overwrites the field in place.
+/// let resize = enum_reflect_extetn::DynamicEnum {
+///     variant: "Circle".to_string(),
+///     fields: [("radius".to_string(), Box::new(2.0_f64) as Box<dyn std::any::Any>)].into(),
+/// };
+/// shape.apply(resize).unwrap();
+/// assert_eq!(shape.as_circle(), Some(&2.0));
+///
+/// // Cross-variant patch replaces `self` wholesale via `from_dynamic`.
+/// let become_square = enum_reflect_extetn::DynamicEnum {
+///     variant: "Square".to_string(),
+///     fields: [("side".to_string(), Box::new(3.0_f64) as Box<dyn std::any::Any>)].into(),
+/// };
+/// shape.apply(become_square).unwrap();
+/// assert!(shape.is_square());
+///
+/// // A patch missing a required field is rejected, leaving `self` unchanged.
+/// let incomplete = enum_reflect_extetn::DynamicEnum {
+///     variant: "Square".to_string(),
+///     fields: Default::default(),
+/// };
+/// assert!(shape.apply(incomplete).is_err());
+/// ```
+///
+/// ```ignore
+/// #[derive(EnumReflect)]
+/// enum Credential {
+///     Public { name: String },
+///     #[reflect(skip)]
+///     Secret { token: String },
+/// }
+///
+/// // A skipped variant can't be reached through `DynamicEnum` at all.
+/// let forged = enum_reflect_extetn::DynamicEnum {
+///     variant: "Secret".to_string(),
+///     fields: [("token".to_string(), Box::new("leaked".to_string()) as Box<dyn std::any::Any>)].into(),
+/// };
+/// assert!(Credential::from_dynamic(forged).is_err());
+///
+/// let mut secret = Credential::Secret { token: "xyz".to_string() };
+/// let overwrite = enum_reflect_extetn::DynamicEnum {
+///     variant: "Secret".to_string(),
+///     fields: [("token".to_string(), Box::new("leaked".to_string()) as Box<dyn std::any::Any>)].into(),
+/// };
+/// assert!(secret.apply(overwrite).is_err());
+/// ```
+///
+/// # Typed field access by name
+///
+/// `get_field_as`/`set_field` wrap the `dyn Any` lookups from the `EnumReflect` trait
+/// so callers don't have to walk `get_named_fields_mut()` and downcast by hand:
+///
+/// - `fn get_field_as<T: 'static>(&self, name: &str) -> Option<&T>`
+/// - `fn set_field<T: 'static>(&mut self, name: &str, value: T) -> Result<(), T>` Returns
+///   `value` back in `Err` when `name` is absent or `T` doesn't match the field's type,
+///   so no data is lost.
+///
 /// # Example
 /// 
 /// ```
@@ -49,7 +575,7 @@ use syn::{parse_macro_input, DeriveInput, Data, Fields, Ident};
 /// - `Field var1 is Hello, World!`
 /// - `Field var2 is 32`
 ///
-#[proc_macro_derive(EnumReflect)]
+#[proc_macro_derive(EnumReflect, attributes(reflect))]
 pub fn enum_reflection(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -62,185 +588,251 @@ pub fn enum_reflection(input: TokenStream) -> TokenStream {
 
     // For get_fields()
     let get_fields_arms = data_enum.variants.iter().map(|v| {
-        let variant_ident = &v.ident;
+        let plan = plan_variant(v);
+        let pattern = &plan.pattern;
 
-        match &v.fields {
-            Fields::Named(fields_named) => {
-                let bindings: Vec<_> = fields_named.named.iter().map(|f| {
-                    let ident = f.ident.as_ref().unwrap();
-                    quote! { #ident }
-                }).collect();
+        let refs: Vec<_> = plan.fields.iter().map(|f| {
+            let ident = &f.binding;
+            quote! { #ident as &dyn std::any::Any }
+        }).collect();
 
-                let refs: Vec<_> = bindings.iter().map(|ident| {
-                    quote! { #ident as &dyn std::any::Any }
-                }).collect();
+        quote! {
+            #name::#pattern => vec![#(#refs),*],
+        }
+    });
 
-                quote! {
-                    #name::#variant_ident { #(#bindings),* } => vec![#(#refs),*],
-                }
-            }
+    // For get_named_fields()
+    let get_named_fields_arms = data_enum.variants.iter().map(|v| {
+        let plan = plan_variant(v);
+        let pattern = &plan.pattern;
 
-            Fields::Unnamed(fields_unnamed) => {
-                let bindings: Vec<_> = (0..fields_unnamed.unnamed.len())
-                    .map(|i| syn::Ident::new(&format!("f{}", i), v.ident.span()))
-                    .collect();
+        let pairs: Vec<_> = plan.fields.iter().map(|f| {
+            let ident = &f.binding;
+            let name_str = &f.display_name;
+            quote! { (#name_str, #ident as &dyn std::any::Any) }
+        }).collect();
 
-                let refs: Vec<_> = bindings.iter().map(|ident| {
-                    quote! { #ident as &dyn std::any::Any }
-                }).collect();
+        quote! {
+            #name::#pattern => vec![#(#pairs),*],
+        }
+    });
 
-                quote! {
-                    #name::#variant_ident( #(#bindings),* ) => vec![#(#refs),*],
-                }
-            }
+    // For get_fields_mut()
+    let get_fields_mut_arms = data_enum.variants.iter().map(|v| {
+        let plan = plan_variant(v);
+        let pattern = &plan.pattern;
 
-            Fields::Unit => {
-                quote! {
-                    #name::#variant_ident => vec![],
-                }
-            }
+        let refs: Vec<_> = plan.fields.iter().map(|f| {
+            let ident = &f.binding;
+            quote! { #ident as &mut dyn std::any::Any }
+        }).collect();
+
+        quote! {
+            #name::#pattern => vec![#(#refs),*],
         }
     });
 
-    // For get_named_fields()
-    let get_named_fields_arms = data_enum.variants.iter().map(|v| {
-        let variant_ident = &v.ident;
+    // For get_named_fields_mut()
+    let get_named_fields_mut_arms = data_enum.variants.iter().map(|v| {
+        let plan = plan_variant(v);
+        let pattern = &plan.pattern;
 
-        match &v.fields {
-            Fields::Named(fields_named) => {
-                let bindings: Vec<_> = fields_named.named.iter().map(|f| {
-                    let ident = f.ident.as_ref().unwrap();
-                    quote! { #ident }
-                }).collect();
+        let pairs: Vec<_> = plan.fields.iter().map(|f| {
+            let ident = &f.binding;
+            let name_str = &f.display_name;
+            quote! { (#name_str, #ident as &mut dyn std::any::Any) }
+        }).collect();
 
-                let pairs: Vec<_> = fields_named.named.iter().map(|f| {
-                    let ident = f.ident.as_ref().unwrap();
-                    let name_str = ident.to_string();
-                    quote! { (#name_str, #ident as &dyn std::any::Any) }
-                }).collect();
+        quote! {
+            #name::#pattern => vec![#(#pairs),*],
+        }
+    });
 
-                quote! {
-                    #name::#variant_ident { #(#bindings),* } => vec![#(#pairs),*],
-                }
-            }
+    // For variant_name()
+    let variant_name_arms = data_enum.variants.iter().map(|v| {
+        let wildcard = wildcard_pattern(v);
+        let variant_str = v.ident.to_string();
+        quote! { #name::#wildcard => #variant_str, }
+    });
 
-            Fields::Unnamed(_) => {
-                // You can skip unnamed fields for `get_named_fields`
-                quote! {
-                    #name::#variant_ident(..) => vec![],
-                }
+    // For field_len()
+    let field_len_arms = data_enum.variants.iter().map(|v| {
+        let wildcard = wildcard_pattern(v);
+        let len = plan_variant(v).fields.len();
+        quote! { #name::#wildcard => #len, }
+    });
+
+    // For field()/field_mut(), by-name lookup. The lookup parameter is named `__name`
+    // (not `name`) so it can't be shadowed by a bound field that happens to be called
+    // `name` itself — that would otherwise make `match name { "name" => ... }` compare
+    // the wrong thing, or fail to type-check at all.
+    let field_by_name_arms = |mutable: bool| {
+        let any_ref = if mutable {
+            quote! { &mut dyn std::any::Any }
+        } else {
+            quote! { &dyn std::any::Any }
+        };
+
+        data_enum.variants.iter().map(move |v| {
+            let plan = plan_variant(v);
+            let pattern = &plan.pattern;
+            let any_ref = any_ref.clone();
+
+            let match_arms: Vec<_> = plan.fields.iter().map(|f| {
+                let ident = &f.binding;
+                let name_str = &f.display_name;
+                quote! { #name_str => Some(#ident as #any_ref), }
+            }).collect();
+
+            quote! {
+                #name::#pattern => match __name {
+                    #(#match_arms)*
+                    _ => None,
+                },
             }
+        })
+    };
 
-            Fields::Unit => {
-                quote! {
-                    #name::#variant_ident => vec![],
-                }
+    let field_arms = field_by_name_arms(false);
+    let field_mut_arms = field_by_name_arms(true);
+
+    // For field_at()/field_at_mut(), by-index lookup. Same shadowing hazard as above,
+    // so the lookup parameter is `__index` rather than `index`.
+    let field_by_index_arms = |mutable: bool| {
+        let any_ref = if mutable {
+            quote! { &mut dyn std::any::Any }
+        } else {
+            quote! { &dyn std::any::Any }
+        };
+
+        data_enum.variants.iter().map(move |v| {
+            let plan = plan_variant(v);
+            let pattern = &plan.pattern;
+            let any_ref = any_ref.clone();
+
+            let match_arms: Vec<_> = plan.fields.iter().enumerate().map(|(i, f)| {
+                let ident = &f.binding;
+                quote! { #i => Some(#ident as #any_ref), }
+            }).collect();
+
+            quote! {
+                #name::#pattern => match __index {
+                    #(#match_arms)*
+                    _ => None,
+                },
             }
-        }
-    });
+        })
+    };
 
-    // For get_fields_mut()
-    let get_fields_mut_arms = data_enum.variants.iter().map(|v| {
-        let variant_ident = &v.ident;
+    let field_at_arms = field_by_index_arms(false);
+    let field_at_mut_arms = field_by_index_arms(true);
 
-        match &v.fields {
-            Fields::Named(fields_named) => {
-                let bindings: Vec<_> = fields_named.named.iter().map(|f| {
-                    let ident = f.ident.as_ref().unwrap();
-                    quote! { #ident }
-                }).collect();
+    // For is_*()/as_*()/as_*_mut()/into_*()
+    let variant_accessors = data_enum.variants.iter().map(|v| variant_accessor_methods(name, v));
 
-                let refs: Vec<_> = bindings.iter().map(|ident| {
-                    quote! { #ident as &mut dyn std::any::Any }
-                }).collect();
+    // For apply()/from_dynamic()
+    let apply_arms = data_enum.variants.iter().map(|v| apply_same_variant_arm(name, v));
+    let from_dynamic_arms = data_enum.variants.iter().map(|v| from_dynamic_arm(name, v));
 
-                quote! {
-                    #name::#variant_ident { #(#bindings),* } => vec![#(#refs),*]
+    let expanded = quote! {
+        impl #name {
+            #(#variant_accessors)*
+
+            pub fn apply(&mut self, mut patch: enum_reflect_extetn::DynamicEnum) -> Result<(), enum_reflect_extetn::DynamicEnumError> {
+                if patch.variant == <Self as enum_reflect_extetn::EnumReflect>::variant_name(self) {
+                    match self {
+                        #(#apply_arms)*
+                    }
+                    Ok(())
+                } else {
+                    *self = Self::from_dynamic(patch)?;
+                    Ok(())
                 }
             }
 
-            Fields::Unnamed(fields_unnamed) => {
-                let bindings: Vec<_> = (0..fields_unnamed.unnamed.len())
-                    .map(|i| Ident::new(&format!("f{}", i), v.ident.span()))
-                    .map(|ident| quote! { #ident })
-                    .collect();
-
-                let refs: Vec<_> = bindings.iter().map(|ident| {
-                    quote! { #ident as &mut dyn std::any::Any }
-                }).collect();
-
-                quote! {
-                    #name::#variant_ident( #(#bindings),* ) => vec![#(#refs),*]
+            pub fn from_dynamic(mut dynamic: enum_reflect_extetn::DynamicEnum) -> Result<Self, enum_reflect_extetn::DynamicEnumError> {
+                match dynamic.variant.as_str() {
+                    #(#from_dynamic_arms)*
+                    other => Err(enum_reflect_extetn::DynamicEnumError::UnknownVariant(other.to_string())),
                 }
             }
 
-            Fields::Unit => {
-                quote! {
-                    #name::#variant_ident => vec![]
+            pub fn get_field_as<T: 'static>(&self, name: &str) -> Option<&T> {
+                <Self as enum_reflect_extetn::EnumReflect>::field(self, name)?.downcast_ref::<T>()
+            }
+
+            pub fn set_field<T: 'static>(&mut self, name: &str, value: T) -> Result<(), T> {
+                match <Self as enum_reflect_extetn::EnumReflect>::field_mut(self, name) {
+                    Some(field) => match field.downcast_mut::<T>() {
+                        Some(slot) => {
+                            *slot = value;
+                            Ok(())
+                        }
+                        None => Err(value),
+                    },
+                    None => Err(value),
                 }
             }
         }
-    });
 
-    // For get_named_fields_mut()
-    let get_named_fields_mut_arms = data_enum.variants.iter().map(|v| {
-        let variant_ident = &v.ident;
+        impl enum_reflect_extetn::EnumReflect for #name {
+            fn get_fields(&self) -> Vec<&dyn std::any::Any> {
+                match self {
+                    #(#get_fields_arms)*
+                }
+            }
 
-        match &v.fields {
-            Fields::Named(fields_named) => {
-                let bindings: Vec<_> = fields_named.named.iter().map(|f| {
-                    let ident = f.ident.as_ref().unwrap();
-                    quote! { #ident }
-                }).collect();
+            fn get_named_fields(&self) -> Vec<(&'static str, &dyn std::any::Any)> {
+                match self {
+                    #(#get_named_fields_arms)*
+                }
+            }
 
-                let pairs: Vec<_> = fields_named.named.iter().map(|f| {
-                    let ident = f.ident.as_ref().unwrap();
-                    let name_str = ident.to_string();
-                    quote! { (#name_str, #ident as &mut dyn std::any::Any) }
-                }).collect();
+            fn get_fields_mut(&mut self) -> Vec<&mut dyn std::any::Any> {
+                match self {
+                    #(#get_fields_mut_arms)*
+                }
+            }
 
-                quote! {
-                    #name::#variant_ident { #(#bindings),* } => vec![#(#pairs),*]
+            fn get_named_fields_mut(&mut self) -> Vec<(&'static str, &mut dyn std::any::Any)> {
+                match self {
+                    #(#get_named_fields_mut_arms)*
                 }
             }
 
-            Fields::Unnamed(_) => {
-                quote! {
-                    #name::#variant_ident(..) => vec![]
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    #(#variant_name_arms)*
                 }
             }
 
-            Fields::Unit => {
-                quote! {
-                    #name::#variant_ident => vec![]
+            fn field_len(&self) -> usize {
+                match self {
+                    #(#field_len_arms)*
                 }
             }
-        }
-    });
 
-    let expanded = quote! {
-        impl enum_reflect_extetn::EnumReflect for #name {
-            pub fn get_fields(&self) -> Vec<&dyn std::any::Any> {
+            fn field(&self, __name: &str) -> Option<&dyn std::any::Any> {
                 match self {
-                    #(#get_fields_arms)*
+                    #(#field_arms)*
                 }
             }
 
-            pub fn get_named_fields(&self) -> Vec<(&'static str, &dyn std::any::Any)> {
+            fn field_at(&self, __index: usize) -> Option<&dyn std::any::Any> {
                 match self {
-                    #(#get_named_fields_arms)*
+                    #(#field_at_arms)*
                 }
             }
 
-            pub fn get_fields_mut(&mut self) -> Vec<&mut dyn std::any::Any> {
+            fn field_mut(&mut self, __name: &str) -> Option<&mut dyn std::any::Any> {
                 match self {
-                    #(#get_fields_mut_arms),*
+                    #(#field_mut_arms)*
                 }
             }
 
-            pub fn get_named_fields_mut(&mut self) -> Vec<(&'static str, &mut dyn std::any::Any)> {
+            fn field_at_mut(&mut self, __index: usize) -> Option<&mut dyn std::any::Any> {
                 match self {
-                    #(#get_named_fields_mut_arms),*
+                    #(#field_at_mut_arms)*
                 }
             }
         }